@@ -0,0 +1,61 @@
+//! Compares writing start, text and end events one fragment at a time against
+//! `Writer::write_event_async`, which writes each of these events with a single
+//! `write_all`. CDATA events are still written with three `write_all` calls.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use quick_xml::events::{BytesStart, BytesText, Event};
+use quick_xml::Writer;
+use tokio::io::AsyncWriteExt;
+use tokio::runtime::Runtime;
+
+fn write_coalesced(rt: &Runtime, iterations: usize) {
+    rt.block_on(async {
+        let mut buffer = Vec::new();
+        let mut writer = Writer::new(&mut buffer);
+        for i in 0..iterations {
+            let name = format!("item{i}");
+            writer
+                .write_event_async(Event::Start(BytesStart::new(&name)))
+                .await
+                .unwrap();
+            writer
+                .write_event_async(Event::Text(BytesText::new("value")))
+                .await
+                .unwrap();
+            writer
+                .write_event_async(Event::End(BytesStart::new(&name).to_end().into_owned()))
+                .await
+                .unwrap();
+        }
+    });
+}
+
+/// Writes the same events directly against the underlying `AsyncWrite`, issuing one
+/// `write_all` per fragment, as `write_wrapped_async` did before coalescing.
+fn write_per_fragment(rt: &Runtime, iterations: usize) {
+    rt.block_on(async {
+        let mut buffer = Vec::new();
+        for i in 0..iterations {
+            let name = format!("item{i}");
+            buffer.write_all(b"<").await.unwrap();
+            buffer.write_all(name.as_bytes()).await.unwrap();
+            buffer.write_all(b">").await.unwrap();
+            buffer.write_all(b"value").await.unwrap();
+            buffer.write_all(b"</").await.unwrap();
+            buffer.write_all(name.as_bytes()).await.unwrap();
+            buffer.write_all(b">").await.unwrap();
+        }
+    });
+}
+
+fn bench_async_writer(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+
+    let mut group = c.benchmark_group("async_writer");
+    group.bench_function("coalesced", |b| b.iter(|| write_coalesced(&rt, 1000)));
+    group.bench_function("per_fragment", |b| b.iter(|| write_per_fragment(&rt, 1000)));
+    group.finish();
+}
+
+criterion_group!(benches, bench_async_writer);
+criterion_main!(benches);
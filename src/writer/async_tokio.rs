@@ -1,7 +1,12 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+
 use tokio::io::{AsyncWrite, AsyncWriteExt};
 
 use crate::errors::Result;
-use crate::events::Event;
+use crate::events::attributes::Attribute;
+use crate::events::{BytesStart, BytesText, Event};
 use crate::Writer;
 
 impl<W: AsyncWrite + Unpin> Writer<W> {
@@ -57,6 +62,16 @@ impl<W: AsyncWrite + Unpin> Writer<W> {
         Ok(())
     }
 
+    /// Flushes the underlying writer. Async version of [`AsyncWriteExt::flush`].
+    pub async fn flush_async(&mut self) -> Result<()> {
+        self.writer.flush().await.map_err(Into::into)
+    }
+
+    /// Shuts down the underlying writer. Async version of [`AsyncWriteExt::shutdown`].
+    pub async fn shutdown_async(&mut self) -> Result<()> {
+        self.writer.shutdown().await.map_err(Into::into)
+    }
+
     #[inline]
     async fn write_async(&mut self, value: &[u8]) -> Result<()> {
         self.writer.write_all(value).await.map_err(Into::into)
@@ -69,16 +84,264 @@ impl<W: AsyncWrite + Unpin> Writer<W> {
         value: &[u8],
         after: &[u8],
     ) -> Result<()> {
+        self.scratch.clear();
         if let Some(ref i) = self.indent {
             if i.should_line_break {
-                self.writer.write_all(b"\n").await?;
-                self.writer.write_all(i.current()).await?;
+                self.scratch.push(b'\n');
+                self.scratch.extend_from_slice(i.current());
             }
         }
-        self.write_async(before).await?;
-        self.write_async(value).await?;
-        self.write_async(after).await?;
-        Ok(())
+        self.scratch.extend_from_slice(before);
+        self.scratch.extend_from_slice(value);
+        self.scratch.extend_from_slice(after);
+        self.writer
+            .write_all(&self.scratch)
+            .await
+            .map_err(Into::into)
+    }
+
+    /// Create an [`ElementWriter`] that will write an element with given name
+    /// asynchronously. Async version of [`Writer::create_element`].
+    pub fn create_element<'a, N>(&'a mut self, name: N) -> ElementWriter<'a, W>
+    where
+        N: Into<Cow<'a, str>>,
+    {
+        ElementWriter {
+            writer: self,
+            start_tag: BytesStart::new(name),
+        }
+    }
+}
+
+/// A builder to write an element asynchronously. Created by [`Writer::create_element`].
+///
+/// Async version of the synchronous `ElementWriter`.
+pub struct ElementWriter<'a, W> {
+    writer: &'a mut Writer<W>,
+    start_tag: BytesStart<'a>,
+}
+
+impl<'a, W: AsyncWrite + Unpin> ElementWriter<'a, W> {
+    /// Adds an attribute to this element.
+    pub fn with_attribute<'b, I>(mut self, attr: I) -> Self
+    where
+        I: Into<Attribute<'b>>,
+    {
+        self.start_tag.push_attribute(attr);
+        self
+    }
+
+    /// Adds several attributes to this element.
+    pub fn with_attributes<'b, I>(mut self, attributes: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Attribute<'b>>,
+    {
+        self.start_tag.extend_attributes(attributes);
+        self
+    }
+
+    /// Writes the given element as an empty tag (`Event::Empty`). Async version of
+    /// `ElementWriter::write_empty`.
+    pub async fn write_empty_async(self) -> Result<&'a mut Writer<W>> {
+        self.writer
+            .write_event_async(Event::Empty(self.start_tag))
+            .await?;
+        Ok(self.writer)
+    }
+
+    /// Writes out the start tag, the given text as a single `Event::Text` event, and
+    /// the matching end tag. Async version of `ElementWriter::write_text_content`.
+    pub async fn write_text_content_async(self, text: BytesText<'_>) -> Result<&'a mut Writer<W>> {
+        self.writer
+            .write_event_async(Event::Start(self.start_tag.borrow()))
+            .await?;
+        self.writer.write_event_async(Event::Text(text)).await?;
+        self.writer
+            .write_event_async(Event::End(self.start_tag.to_end()))
+            .await?;
+        Ok(self.writer)
+    }
+
+    /// Writes out the start tag, then awaits `closure` to write any children into this
+    /// writer, then writes the matching end tag. The future returned by `closure` must
+    /// yield the writer it was given back. Async version of
+    /// `ElementWriter::write_inner_content`.
+    pub async fn write_inner_content_async<F, Fut>(self, closure: F) -> Result<&'a mut Writer<W>>
+    where
+        F: FnOnce(&'a mut Writer<W>) -> Fut,
+        Fut: Future<Output = Result<&'a mut Writer<W>>>,
+    {
+        self.writer
+            .write_event_async(Event::Start(self.start_tag.borrow()))
+            .await?;
+        let writer = closure(self.writer).await?;
+        writer
+            .write_event_async(Event::End(self.start_tag.to_end()))
+            .await?;
+        Ok(writer)
+    }
+}
+
+/// The prefixes declared on one open element, so they can be un-bound again once
+/// that element's matching end tag is written.
+#[derive(Debug, Default)]
+struct NamespaceFrame {
+    declared: Vec<String>,
+}
+
+/// Tracks in-scope `prefix` → `URI` bindings, mirroring the element nesting driven
+/// by [`Event::Start`]/[`Event::End`].
+#[derive(Debug, Default)]
+pub struct NamespaceScope {
+    /// All bindings ever registered via [`NamespaceScope::bind_prefix`].
+    registered: HashMap<String, String>,
+    /// Prefixes that are currently declared somewhere on the open-element stack.
+    in_scope: HashSet<String>,
+    /// One frame per currently open element.
+    frames: Vec<NamespaceFrame>,
+}
+
+impl NamespaceScope {
+    /// Creates an empty scope with no registered bindings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `prefix` to resolve to `uri`.
+    pub fn bind_prefix(&mut self, prefix: impl Into<String>, uri: impl Into<String>) {
+        self.registered.insert(prefix.into(), uri.into());
+    }
+
+    /// Resolves `prefix` to its registered URI, if any.
+    pub fn resolve(&self, prefix: &str) -> Option<&str> {
+        self.registered.get(prefix).map(String::as_str)
+    }
+
+    /// Qualifies `local_name` with `prefix`, returning `prefix:local_name`.
+    pub fn qualify(&self, prefix: &str, local_name: &str) -> String {
+        format!("{prefix}:{local_name}")
+    }
+
+    fn prefix_of(name: &str) -> Option<&str> {
+        name.split_once(':').map(|(prefix, _)| prefix)
+    }
+
+    /// Called before writing a start or empty tag with the given element and
+    /// attribute names. Returns the `xmlns:*` declarations that must be injected
+    /// because their prefix is not yet in scope, pushing a new frame that
+    /// remembers them.
+    fn enter_element<'n>(
+        &mut self,
+        names: impl IntoIterator<Item = &'n str>,
+    ) -> Vec<(String, String)> {
+        let mut frame = NamespaceFrame::default();
+        let mut to_declare = Vec::new();
+
+        for name in names {
+            let Some(prefix) = Self::prefix_of(name) else {
+                continue;
+            };
+            if self.in_scope.contains(prefix) || frame.declared.iter().any(|p| p == prefix) {
+                continue;
+            }
+            if let Some(uri) = self.registered.get(prefix).cloned() {
+                self.in_scope.insert(prefix.to_string());
+                frame.declared.push(prefix.to_string());
+                to_declare.push((format!("xmlns:{prefix}"), uri));
+            }
+        }
+
+        self.frames.push(frame);
+        to_declare
+    }
+
+    /// Called after writing the end tag (or in place of one, for an empty tag)
+    /// that matches the most recently entered element, un-binding any prefixes
+    /// it declared.
+    fn exit_element(&mut self) {
+        if let Some(frame) = self.frames.pop() {
+            for prefix in frame.declared {
+                self.in_scope.remove(&prefix);
+            }
+        }
+    }
+}
+
+/// A namespace-aware wrapper around an async [`Writer`] that automatically injects
+/// and scopes `xmlns:*` declarations for prefixes bound via [`NamespaceWriter::bind_prefix`].
+pub struct NamespaceWriter<W> {
+    writer: Writer<W>,
+    scope: NamespaceScope,
+}
+
+impl<W> NamespaceWriter<W> {
+    /// Wraps `writer` with namespace-scope tracking.
+    pub fn new(writer: Writer<W>) -> Self {
+        Self {
+            writer,
+            scope: NamespaceScope::new(),
+        }
+    }
+
+    /// Registers `prefix` to resolve to `uri` for the lifetime of this writer.
+    pub fn bind_prefix(&mut self, prefix: impl Into<String>, uri: impl Into<String>) {
+        self.scope.bind_prefix(prefix, uri);
+    }
+
+    /// Gets a reference to the namespace scope, to resolve or qualify names.
+    pub fn scope(&self) -> &NamespaceScope {
+        &self.scope
+    }
+
+    /// Unwraps this writer, discarding namespace-scope tracking.
+    pub fn into_inner(self) -> Writer<W> {
+        self.writer
+    }
+}
+
+impl<W: AsyncWrite + Unpin> NamespaceWriter<W> {
+    /// Writes `event`, automatically injecting `xmlns:*` declarations for any bound
+    /// prefix used by the element name or its attributes that is not yet in scope,
+    /// and popping those declarations again once the matching end (or empty) tag
+    /// is written.
+    pub async fn write_event_async<'a>(&mut self, event: Event<'a>) -> Result<()> {
+        match event {
+            Event::Start(start) => {
+                let start = self.declare_namespaces(start);
+                self.writer.write_event_async(Event::Start(start)).await
+            }
+            Event::Empty(start) => {
+                let start = self.declare_namespaces(start);
+                let result = self.writer.write_event_async(Event::Empty(start)).await;
+                self.scope.exit_element();
+                result
+            }
+            Event::End(end) => {
+                let result = self.writer.write_event_async(Event::End(end)).await;
+                self.scope.exit_element();
+                result
+            }
+            other => self.writer.write_event_async(other).await,
+        }
+    }
+
+    fn declare_namespaces<'a>(&mut self, mut start: BytesStart<'a>) -> BytesStart<'a> {
+        let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+        let attr_names: Vec<String> = start
+            .attributes()
+            .filter_map(|a| a.ok())
+            .map(|a| String::from_utf8_lossy(a.key.as_ref()).into_owned())
+            .collect();
+
+        let mut candidates = Vec::with_capacity(1 + attr_names.len());
+        candidates.push(name.as_str());
+        candidates.extend(attr_names.iter().map(String::as_str));
+
+        for (attr, uri) in self.scope.enter_element(candidates) {
+            start.push_attribute((attr.as_str(), uri.as_str()));
+        }
+        start
     }
 }
 
@@ -154,6 +417,204 @@ mod tests {
             r#"<tag>inner text</tag>"#
         );
     }
+
+    #[tokio::test]
+    async fn accessors_and_flush() {
+        let mut writer = Writer::new(Vec::new());
+
+        writer
+            .write_event_async(Event::Empty(BytesStart::new("tag")))
+            .await
+            .expect("write tag failed");
+        writer.flush_async().await.expect("flush failed");
+
+        assert_eq!(writer.get_ref().as_slice(), b"<tag/>");
+        writer.get_mut().extend_from_slice(b"!");
+        assert_eq!(writer.into_inner().as_slice(), b"<tag/>!");
+    }
+
+    #[tokio::test]
+    async fn accessors_reachable_on_async_only_writer() {
+        // `DuplexStream` implements `AsyncWrite` but not `std::io::Write`, so this only
+        // compiles if `get_ref`/`get_mut`/`into_inner` are reachable without a `Write`
+        // bound on `W`.
+        let (client, _server) = tokio::io::duplex(64);
+        let mut writer = Writer::new(client);
+
+        writer
+            .write_event_async(Event::Empty(BytesStart::new("tag")))
+            .await
+            .expect("write tag failed");
+        writer.flush_async().await.expect("flush failed");
+
+        let _: &tokio::io::DuplexStream = writer.get_ref();
+        let _: &mut tokio::io::DuplexStream = writer.get_mut();
+        let _: tokio::io::DuplexStream = writer.into_inner();
+    }
+}
+
+#[cfg(test)]
+mod element_writer_async {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn write_empty_async() {
+        let mut buffer = Vec::new();
+        let mut writer = Writer::new(&mut buffer);
+
+        writer
+            .create_element("tag")
+            .with_attribute(("attr1", "value1"))
+            .with_attributes([("attr2", "value2")])
+            .write_empty_async()
+            .await
+            .expect("write empty tag failed");
+
+        assert_eq!(
+            std::str::from_utf8(&buffer).unwrap(),
+            r#"<tag attr1="value1" attr2="value2"/>"#
+        );
+    }
+
+    #[tokio::test]
+    async fn write_text_content_async() {
+        let mut buffer = Vec::new();
+        let mut writer = Writer::new(&mut buffer);
+
+        writer
+            .create_element("tag")
+            .write_text_content_async(BytesText::new("text"))
+            .await
+            .expect("write text content failed");
+
+        assert_eq!(std::str::from_utf8(&buffer).unwrap(), r#"<tag>text</tag>"#);
+    }
+
+    #[tokio::test]
+    async fn write_inner_content_async() {
+        let mut buffer = Vec::new();
+        let mut writer = Writer::new(&mut buffer);
+
+        writer
+            .create_element("outer")
+            .write_inner_content_async(|writer| async move {
+                writer.create_element("inner").write_empty_async().await?;
+                Ok(writer)
+            })
+            .await
+            .expect("write inner content failed");
+
+        assert_eq!(
+            std::str::from_utf8(&buffer).unwrap(),
+            r#"<outer><inner/></outer>"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod namespace_writer_async {
+    use super::*;
+    use crate::events::BytesEnd;
+    use pretty_assertions::assert_eq;
+
+    #[tokio::test]
+    async fn declares_prefix_once_on_root() {
+        let mut buffer = Vec::new();
+        let mut writer = NamespaceWriter::new(Writer::new(&mut buffer));
+        writer.bind_prefix("D", "DAV:");
+
+        writer
+            .write_event_async(Event::Empty(BytesStart::new("D:propfind")))
+            .await
+            .expect("write empty tag failed");
+
+        assert_eq!(
+            std::str::from_utf8(&buffer).unwrap(),
+            r#"<D:propfind xmlns:D="DAV:"/>"#
+        );
+    }
+
+    #[tokio::test]
+    async fn declares_prefix_used_only_by_an_attribute() {
+        let mut buffer = Vec::new();
+        let mut writer = NamespaceWriter::new(Writer::new(&mut buffer));
+        writer.bind_prefix("D", "DAV:");
+        writer.bind_prefix("C", "urn:ietf:params:xml:ns:caldav");
+
+        let tag = BytesStart::new("propfind").with_attributes([("C:token", "x")]);
+        writer
+            .write_event_async(Event::Empty(tag))
+            .await
+            .expect("write empty tag failed");
+
+        assert_eq!(
+            std::str::from_utf8(&buffer).unwrap(),
+            r#"<propfind C:token="x" xmlns:C="urn:ietf:params:xml:ns:caldav"/>"#
+        );
+    }
+
+    #[tokio::test]
+    async fn does_not_redeclare_in_nested_descendant() {
+        let mut buffer = Vec::new();
+        let mut writer = NamespaceWriter::new(Writer::new(&mut buffer));
+        writer.bind_prefix("D", "DAV:");
+
+        writer
+            .write_event_async(Event::Start(BytesStart::new("D:multistatus")))
+            .await
+            .expect("write start tag failed");
+        writer
+            .write_event_async(Event::Empty(BytesStart::new("D:response")))
+            .await
+            .expect("write inner tag failed");
+        writer
+            .write_event_async(Event::End(BytesEnd::new("D:multistatus")))
+            .await
+            .expect("write end tag failed");
+
+        assert_eq!(
+            std::str::from_utf8(&buffer).unwrap(),
+            r#"<D:multistatus xmlns:D="DAV:"><D:response/></D:multistatus>"#
+        );
+    }
+
+    #[tokio::test]
+    async fn redeclares_in_sibling_subtree_after_scope_closes() {
+        let mut buffer = Vec::new();
+        let mut writer = NamespaceWriter::new(Writer::new(&mut buffer));
+        writer.bind_prefix("D", "DAV:");
+
+        writer
+            .write_event_async(Event::Empty(BytesStart::new("D:a")))
+            .await
+            .expect("write first tag failed");
+        writer
+            .write_event_async(Event::Empty(BytesStart::new("D:b")))
+            .await
+            .expect("write second tag failed");
+
+        assert_eq!(
+            std::str::from_utf8(&buffer).unwrap(),
+            r#"<D:a xmlns:D="DAV:"/><D:b xmlns:D="DAV:"/>"#
+        );
+    }
+
+    #[test]
+    fn resolves_bound_prefix_via_scope() {
+        let mut writer = NamespaceWriter::new(Writer::new(Vec::new()));
+        writer.bind_prefix("D", "DAV:");
+
+        assert_eq!(writer.scope().resolve("D"), Some("DAV:"));
+        assert_eq!(writer.scope().resolve("C"), None);
+    }
+
+    #[test]
+    fn qualifies_name_with_prefix() {
+        let writer = NamespaceWriter::new(Writer::new(Vec::new()));
+
+        assert_eq!(writer.scope().qualify("D", "propfind"), "D:propfind");
+    }
 }
 
 #[cfg(test)]